@@ -0,0 +1,47 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use axum::{extract::State, response::IntoResponse, routing::post, Router};
+use tower_http::validate_request::ValidateRequestHeaderLayer;
+
+use crate::content;
+use crate::state::AppState;
+
+/// Builds the `/api` router, guarded by a bearer token read from `API_KEY` at startup.
+///
+/// Returns `None` (and logs a warning) if `API_KEY` isn't set, so deployments
+/// that don't need the admin API can keep booting without adding an unrelated
+/// secret -- the caller simply skips mounting `/api` in that case.
+pub fn admin_router() -> Option<Router<Arc<AppState>>> {
+    let api_key = match std::env::var("API_KEY") {
+        Ok(api_key) => api_key,
+        Err(_) => {
+            tracing::warn!("API_KEY not set, admin API will not be mounted");
+            return None;
+        }
+    };
+
+    Some(
+        Router::new()
+            .route("/reload", post(reload))
+            .layer(ValidateRequestHeaderLayer::bearer(&api_key)),
+    )
+}
+
+/// `POST /api/reload` — re-reads `index.html` and the Markdown content
+/// directory into shared state, without restarting the process.
+async fn reload(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let index_html = load_index_html(&state.static_root.join("index.html")).await;
+    let pages = content::load_pages(&PathBuf::from("content"));
+
+    *state.index_html.write().await = index_html;
+    *state.pages.write().await = pages;
+
+    "reloaded"
+}
+
+pub async fn load_index_html(path: &PathBuf) -> String {
+    tokio::fs::read_to_string(path)
+        .await
+        .unwrap_or_else(|_| "<h1>Error: index.html not found</h1>".to_string())
+}
@@ -0,0 +1,75 @@
+/// Process-wide configuration, populated from environment variables at startup.
+#[derive(Clone, Debug)]
+pub struct AppConfig {
+    /// Path prefixes that should fall through to the SPA shell (`dist/index.html`)
+    /// rather than a genuine 404 page. Defaults to `["/"]`, matching the
+    /// pre-existing "always serve the shell" behavior so a fresh deploy's home
+    /// page keeps working out of the box. Operators who want a real `404` for
+    /// unmatched paths opt in explicitly with `SPA_ROUTE_PREFIXES=""`.
+    pub spa_route_prefixes: Vec<String>,
+}
+
+impl AppConfig {
+    pub fn from_env() -> Self {
+        let spa_route_prefixes = match std::env::var("SPA_ROUTE_PREFIXES") {
+            Ok(raw) => raw
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_owned)
+                .collect::<Vec<_>>(),
+            Err(_) => vec!["/".to_string()],
+        };
+
+        if spa_route_prefixes.is_empty() {
+            tracing::warn!(
+                "SPA_ROUTE_PREFIXES is empty, no path will fall back to the SPA shell"
+            );
+        }
+
+        Self { spa_route_prefixes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_spa_route(config: &AppConfig, path: &str) -> bool {
+        config
+            .spa_route_prefixes
+            .iter()
+            .any(|prefix| path.starts_with(prefix.as_str()))
+    }
+
+    #[test]
+    fn unconfigured_prefixes_default_to_matching_everything() {
+        let config = AppConfig {
+            spa_route_prefixes: vec!["/".to_string()],
+        };
+
+        assert!(is_spa_route(&config, "/"));
+        assert!(is_spa_route(&config, "/dashboard"));
+    }
+
+    #[test]
+    fn an_explicit_empty_override_treats_nothing_as_an_spa_route() {
+        let config = AppConfig {
+            spa_route_prefixes: Vec::new(),
+        };
+
+        assert!(!is_spa_route(&config, "/"));
+        assert!(!is_spa_route(&config, "/dashboard"));
+    }
+
+    #[test]
+    fn configured_prefix_matches_only_paths_starting_with_it() {
+        let config = AppConfig {
+            spa_route_prefixes: vec!["/app".to_string()],
+        };
+
+        assert!(is_spa_route(&config, "/app"));
+        assert!(is_spa_route(&config, "/app/settings"));
+        assert!(!is_spa_route(&config, "/other"));
+    }
+}
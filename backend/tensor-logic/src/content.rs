@@ -0,0 +1,113 @@
+use std::path::Path;
+
+use pulldown_cmark::{html, Options, Parser};
+use serde::Serialize;
+
+/// A single Markdown page, parsed once at startup.
+#[derive(Clone, Debug, Serialize)]
+pub struct Page {
+    pub slug: String,
+    pub title: String,
+    pub html: String,
+}
+
+/// Globs `content_dir/**/*.md` into a preparsed collection of pages.
+///
+/// `index.md` files take the slug of their parent directory, so
+/// `content/guides/index.md` becomes the page for `/pages/guides`.
+pub fn load_pages(content_dir: &Path) -> Vec<Page> {
+    let pattern = content_dir.join("**").join("*.md");
+    let pattern = pattern.to_string_lossy().into_owned();
+
+    glob::glob(&pattern)
+        .expect("invalid content glob pattern")
+        .filter_map(Result::ok)
+        .filter_map(|path| parse_page(content_dir, &path))
+        .collect()
+}
+
+fn parse_page(content_dir: &Path, path: &Path) -> Option<Page> {
+    let markdown = std::fs::read_to_string(path).ok()?;
+    let slug = slug_for(content_dir, path)?;
+    let title = title_for(&markdown, &slug);
+
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+
+    let parser = Parser::new_ext(&markdown, options);
+    let mut html_out = String::new();
+    html::push_html(&mut html_out, parser);
+
+    Some(Page {
+        slug,
+        title,
+        html: html_out,
+    })
+}
+
+fn slug_for(content_dir: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(content_dir).ok()?;
+    let stem = relative.file_stem()?.to_str()?;
+
+    if stem == "index" {
+        relative
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .and_then(|parent| parent.to_str())
+            .map(str::to_owned)
+    } else {
+        relative.with_extension("").to_str().map(str::to_owned)
+    }
+}
+
+fn title_for(markdown: &str, fallback_slug: &str) -> String {
+    markdown
+        .lines()
+        .find_map(|line| line.strip_prefix("# "))
+        .map(str::trim)
+        .map(str::to_owned)
+        .unwrap_or_else(|| fallback_slug.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slug_for_a_top_level_page_drops_the_extension() {
+        let slug = slug_for(Path::new("content"), Path::new("content/about.md"));
+        assert_eq!(slug.as_deref(), Some("about"));
+    }
+
+    #[test]
+    fn slug_for_a_nested_page_keeps_the_subpath() {
+        let slug = slug_for(Path::new("content"), Path::new("content/guides/setup.md"));
+        assert_eq!(slug.as_deref(), Some("guides/setup"));
+    }
+
+    #[test]
+    fn slug_for_an_index_page_uses_its_parent_directory() {
+        let slug = slug_for(Path::new("content"), Path::new("content/guides/index.md"));
+        assert_eq!(slug.as_deref(), Some("guides"));
+    }
+
+    #[test]
+    fn slug_for_the_top_level_index_page_is_none() {
+        let slug = slug_for(Path::new("content"), Path::new("content/index.md"));
+        assert_eq!(slug, None);
+    }
+
+    #[test]
+    fn title_for_uses_the_first_h1() {
+        let title = title_for("# My Title\n\nbody text", "fallback");
+        assert_eq!(title, "My Title");
+    }
+
+    #[test]
+    fn title_for_falls_back_to_the_slug_when_there_is_no_h1() {
+        let title = title_for("no heading here", "guides/setup");
+        assert_eq!(title, "guides/setup");
+    }
+}
@@ -1,36 +1,154 @@
 use axum::{
-    response::Html,
+    extract::State,
+    http::{header, HeaderValue, Method, StatusCode, Uri},
+    middleware,
+    response::{Html, IntoResponse},
     routing::get,
     Router,
 };
+use std::future::ready;
 use std::path::PathBuf;
-use tower_http::services::ServeDir;
+use std::sync::Arc;
+use std::time::Instant;
 use tower::ServiceBuilder;
+use tower_http::compression::CompressionLayer;
+use tower_http::services::ServeDir;
+use tower_http::set_header::SetResponseHeaderLayer;
 use tower_http::trace::TraceLayer;
 
-async fn serve_index() -> Html<String> {
-    let index_path = PathBuf::from("dist/index.html");
-    match tokio::fs::read_to_string(&index_path).await {
-        Ok(content) => Html(content),
-        Err(_) => Html("<h1>Error: index.html not found</h1>".to_string()),
-    }
+mod admin;
+mod config;
+mod content;
+mod metrics;
+mod pages;
+mod redirects;
+mod sites;
+mod state;
+
+use admin::{admin_router, load_index_html};
+use config::AppConfig;
+use metrics::{record_request, setup_metrics_recorder, track_metrics};
+use redirects::{load_redirects, redirect_router};
+use sites::load_sites;
+use state::AppState;
+
+async fn serve_index(state: &AppState) -> Html<String> {
+    Html(state.index_html.read().await.clone())
+}
+
+async fn serve_not_found(state: &AppState) -> impl IntoResponse {
+    let not_found_path = state.static_root.join("404.html");
+    let body = tokio::fs::read_to_string(&not_found_path)
+        .await
+        .unwrap_or_else(|_| "<h1>404 Not Found</h1>".to_string());
+    (StatusCode::NOT_FOUND, Html(body))
+}
+
+/// Falls back to the SPA shell for client-side routes, and a real 404 page otherwise.
+///
+/// Never passes through `route_layer`, so `track_metrics` never sees it; record
+/// its outcome here under a fixed `<fallback>` path label instead of losing it.
+async fn spa_fallback(
+    State(state): State<Arc<AppState>>,
+    method: Method,
+    uri: Uri,
+) -> impl IntoResponse {
+    let start = Instant::now();
+
+    let is_spa_route = state
+        .config
+        .spa_route_prefixes
+        .iter()
+        .any(|prefix| uri.path().starts_with(prefix.as_str()));
+
+    let response = if is_spa_route {
+        serve_index(&state).await.into_response()
+    } else {
+        serve_not_found(&state).await.into_response()
+    };
+
+    record_request(
+        method.as_str(),
+        "<fallback>",
+        response.status().as_u16(),
+        start.elapsed().as_secs_f64(),
+    );
+
+    response
 }
 
 #[shuttle_runtime::main]
 async fn main() -> shuttle_axum::ShuttleAxum {
-    // Serve static files from dist
-    let static_dir = PathBuf::from("dist");
-    
-    // Create router with static file serving and SPA fallback
-    let router = Router::new()
-        // Serve static assets (JS, CSS, fonts, etc.) from /assets
-        .nest_service(
-            "/assets",
-            ServeDir::new(static_dir.join("assets")),
+    // Install the Prometheus recorder once, before any requests come in.
+    let recorder_handle = setup_metrics_recorder();
+
+    let app_config = AppConfig::from_env();
+    let redirects = load_redirects();
+    let site_mounts = load_sites();
+    let static_root = sites::index_root(&site_mounts);
+    let pages = content::load_pages(&PathBuf::from("content"));
+    let index_html = load_index_html(&static_root.join("index.html")).await;
+    let app_state = Arc::new(AppState::new(app_config, static_root, pages, index_html));
+
+    // Mount each configured static root (assets, docs, auxiliary sites, ...),
+    // serving prebuilt .gz/.br variants everywhere. Only the dedicated
+    // `/assets` mount holds content-hashed bundle filenames, so the 1-year
+    // immutable cache header is scoped to it alone -- other sites (docs,
+    // status pages, ...) keep default caching so their deploys stay visible.
+    // The default site's own directory holds `index.html` directly (see
+    // `sites::index_root`), so only its `assets` subdirectory gets mounted.
+    let mut router = Router::new();
+    for site in &site_mounts {
+        let mount_dir = if site.default {
+            site.dir.join("assets")
+        } else {
+            site.dir.clone()
+        };
+        let serve_dir = ServeDir::new(&mount_dir)
+            .precompressed_gzip()
+            .precompressed_br();
+
+        if site.prefix == "/assets" {
+            let cached_serve_dir = ServiceBuilder::new()
+                .layer(SetResponseHeaderLayer::if_not_present(
+                    header::CACHE_CONTROL,
+                    HeaderValue::from_static("public, max-age=31536000, immutable"),
+                ))
+                .service(serve_dir);
+            router = router.nest_service(&site.prefix, cached_serve_dir);
+        } else {
+            router = router.nest_service(&site.prefix, serve_dir);
+        }
+    }
+
+    // Create router with static file serving, configured redirects, and SPA fallback
+    let mut router = router
+        // Scrape-ready Prometheus metrics for operators
+        .route("/metrics", get(move || ready(recorder_handle.render())))
+        // Preparsed Markdown content, rendered server-side
+        .route("/pages/", get(pages::list_pages))
+        .route("/pages/*slug", get(pages::show_page));
+
+    // Protected admin API for triggering reloads without a restart; only
+    // mounted when an API_KEY is configured to guard it.
+    if let Some(api) = admin_router() {
+        router = router.nest("/api", api);
+    }
+
+    let router = router
+        // Explicit redirects for moved URLs
+        .merge(redirect_router(&redirects))
+        // SPA shell for client routes, real 404 page otherwise
+        .fallback(spa_fallback)
+        // Only runs for matched routes, so `MatchedPath` is populated by the
+        // time `track_metrics` reads it -- keeps metric label cardinality bounded.
+        .route_layer(middleware::from_fn(track_metrics))
+        .layer(
+            ServiceBuilder::new()
+                .layer(TraceLayer::new_for_http())
+                .layer(CompressionLayer::new()),
         )
-        // Serve index.html for all other routes (SPA routing)
-        .fallback(get(serve_index))
-        .layer(ServiceBuilder::new().layer(TraceLayer::new_for_http()));
+        .with_state(app_state);
 
     Ok(router.into())
 }
@@ -0,0 +1,63 @@
+use std::time::Instant;
+
+use axum::{extract::MatchedPath, http::Request, middleware::Next, response::IntoResponse};
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+
+const EXPONENTIAL_SECONDS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Builds the Prometheus recorder and installs it as the global metrics recorder.
+///
+/// Must be called exactly once during startup, before any `metrics::*!` macros fire.
+pub fn setup_metrics_recorder() -> PrometheusHandle {
+    PrometheusBuilder::new()
+        .set_buckets_for_metric(
+            metrics_exporter_prometheus::Matcher::Full("http_request_duration_seconds".to_string()),
+            EXPONENTIAL_SECONDS,
+        )
+        .expect("failed to set latency buckets")
+        .install_recorder()
+        .expect("failed to install Prometheus recorder")
+}
+
+/// `axum::middleware::from_fn` layer that records per-request latency and status.
+///
+/// Installed via `route_layer`, so it only wraps matched routes -- by the time
+/// it runs, `MatchedPath` is already in the request extensions, keeping the
+/// `path` label bounded to the route patterns rather than every concrete URL.
+/// Unmatched requests fall through to the router's `fallback`, which never
+/// passes through `route_layer`; instrument that separately via
+/// `record_request` with a fixed label instead of skipping it.
+pub async fn track_metrics<B>(req: Request<B>, next: Next<B>) -> impl IntoResponse {
+    let start = Instant::now();
+    let path = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched_path| matched_path.as_str().to_owned())
+        .unwrap_or_else(|| req.uri().path().to_owned());
+    let method = req.method().to_string();
+
+    let response = next.run(req).await;
+
+    let latency = start.elapsed().as_secs_f64();
+    record_request(&method, &path, response.status().as_u16(), latency);
+
+    response
+}
+
+/// Records one request's outcome under `http_requests_total`/
+/// `http_request_duration_seconds`. Shared by `track_metrics` (matched
+/// routes, labeled by route pattern) and the SPA/404 fallback (labeled with
+/// a fixed `<fallback>` path so unmatched traffic stays visible without
+/// blowing up label cardinality).
+pub fn record_request(method: &str, path: &str, status: u16, latency_seconds: f64) {
+    let labels = [
+        ("method", method.to_string()),
+        ("path", path.to_string()),
+        ("status", status.to_string()),
+    ];
+
+    metrics::increment_counter!("http_requests_total", &labels);
+    metrics::histogram!("http_request_duration_seconds", latency_seconds, &labels);
+}
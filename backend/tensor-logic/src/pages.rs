@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use tera::Context;
+
+use crate::state::AppState;
+
+/// `GET /pages/` — an index listing of all known Markdown pages.
+pub async fn list_pages(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let pages = state.pages.read().await;
+
+    let mut context = Context::new();
+    context.insert("pages", &*pages);
+
+    render(&state, "pages_index.html.tera", &context)
+}
+
+/// `GET /pages/*slug` — a single rendered Markdown page, or `404` if unknown.
+///
+/// A catch-all route, since `content::slug_for` produces multi-segment slugs
+/// (e.g. `guides/setup`) for nested pages; axum's wildcard capture hands back
+/// the matched tail, which needs its surrounding slashes trimmed before it
+/// lines up with a stored `Page::slug`.
+pub async fn show_page(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+) -> impl IntoResponse {
+    let slug = slug.trim_matches('/');
+    let pages = state.pages.read().await;
+    let Some(page) = pages.iter().find(|page| page.slug == slug) else {
+        return (StatusCode::NOT_FOUND, Html("<h1>404 Not Found</h1>".to_string())).into_response();
+    };
+
+    let mut context = Context::new();
+    context.insert("page", page);
+
+    render(&state, "page.html.tera", &context).into_response()
+}
+
+fn render(state: &AppState, template: &str, context: &Context) -> axum::response::Response {
+    match state.tera.render(template, context) {
+        Ok(body) => Html(body).into_response(),
+        Err(err) => {
+            tracing::error!(%err, template, "failed to render template");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use axum::{response::Redirect, routing::get, Router};
+
+/// A single configured redirect, e.g. `/old-page -> /new-page` (permanent).
+#[derive(Clone, Debug)]
+pub struct RedirectRule {
+    pub from: String,
+    pub to: String,
+    pub permanent: bool,
+}
+
+/// Loads redirect rules from the `REDIRECTS` environment variable.
+///
+/// Format: semicolon-separated rules of `from -> to [permanent|temporary]`,
+/// e.g. `REDIRECTS="/old -> /new permanent;/blog -> https://blog.example.com temporary"`.
+/// Rules with no explicit mode default to permanent.
+pub fn load_redirects() -> Vec<RedirectRule> {
+    std::env::var("REDIRECTS")
+        .ok()
+        .map(|raw| {
+            raw.split(';')
+                .filter_map(|rule| parse_rule(rule.trim()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn parse_rule(rule: &str) -> Option<RedirectRule> {
+    if rule.is_empty() {
+        return None;
+    }
+    let (route, mode) = rule.split_once(" -> ")?;
+    let mut parts = mode.trim().splitn(2, char::is_whitespace);
+    let to = parts.next()?.to_string();
+    let permanent = !matches!(parts.next(), Some("temporary"));
+
+    Some(RedirectRule {
+        from: route.trim().to_string(),
+        to,
+        permanent,
+    })
+}
+
+/// Builds a router of explicit redirect routes for the given rules.
+pub fn redirect_router<S>(rules: &[RedirectRule]) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    rules.iter().fold(Router::new(), |router, rule| {
+        let to = rule.to.clone();
+        let permanent = rule.permanent;
+        router.route(
+            &rule.from,
+            get(move || async move {
+                if permanent {
+                    Redirect::permanent(&to)
+                } else {
+                    Redirect::temporary(&to)
+                }
+            }),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rule_with_no_explicit_mode_as_permanent() {
+        let rule = parse_rule("/old -> /new").unwrap();
+        assert_eq!(rule.from, "/old");
+        assert_eq!(rule.to, "/new");
+        assert!(rule.permanent);
+    }
+
+    #[test]
+    fn parses_explicit_permanent_mode() {
+        let rule = parse_rule("/old -> /new permanent").unwrap();
+        assert!(rule.permanent);
+    }
+
+    #[test]
+    fn parses_explicit_temporary_mode() {
+        let rule = parse_rule("/old -> /new temporary").unwrap();
+        assert!(!rule.permanent);
+    }
+
+    #[test]
+    fn rejects_an_empty_rule() {
+        assert!(parse_rule("").is_none());
+    }
+
+    #[test]
+    fn rejects_a_rule_missing_the_arrow() {
+        assert!(parse_rule("/old /new").is_none());
+    }
+
+    #[test]
+    fn preserves_a_url_destination_containing_a_scheme() {
+        let rule = parse_rule("/blog -> https://blog.example.com temporary").unwrap();
+        assert_eq!(rule.to, "https://blog.example.com");
+        assert!(!rule.permanent);
+    }
+}
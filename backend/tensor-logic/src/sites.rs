@@ -0,0 +1,123 @@
+use std::path::PathBuf;
+
+/// A single static root mounted at a URL prefix, e.g. `/docs -> dist/docs`.
+///
+/// For the `default` site, `dir` is the site's own root directory (it holds
+/// `index.html`/`404.html` directly); the files actually mounted at `prefix`
+/// live in `dir`'s `assets` subdirectory -- see `main`'s mounting loop.
+#[derive(Clone, Debug)]
+pub struct SiteMapping {
+    pub prefix: String,
+    pub dir: PathBuf,
+    /// Whether this site's directory backs the SPA shell (`index.html`/`404.html`).
+    pub default: bool,
+}
+
+/// Loads static site mappings from the `SITES` environment variable.
+///
+/// Format: semicolon-separated `prefix=dir[:default]` pairs, e.g.
+/// `SITES="/docs=site-docs:default;/status=dist/status"`, where `site-docs`
+/// contains its own `index.html` alongside a `site-docs/assets` directory.
+/// Exactly one mapping should be marked `:default`; if none is, the first
+/// mapping wins. Falls back to the original single `/assets` mount when unset,
+/// so existing single-site deployments need no configuration change.
+pub fn load_sites() -> Vec<SiteMapping> {
+    let mut sites: Vec<SiteMapping> = std::env::var("SITES")
+        .ok()
+        .map(|raw| raw.split(';').filter_map(|entry| parse_site(entry.trim())).collect())
+        .unwrap_or_default();
+
+    if sites.is_empty() {
+        sites.push(SiteMapping {
+            prefix: "/assets".to_string(),
+            dir: PathBuf::from("dist"),
+            default: true,
+        });
+    } else if !sites.iter().any(|site| site.default) {
+        sites[0].default = true;
+    }
+
+    sites
+}
+
+fn parse_site(entry: &str) -> Option<SiteMapping> {
+    if entry.is_empty() {
+        return None;
+    }
+    let (prefix, rest) = entry.split_once('=')?;
+    let (dir, default) = match rest.rsplit_once(':') {
+        Some((dir, "default")) => (dir, true),
+        _ => (rest, false),
+    };
+
+    Some(SiteMapping {
+        prefix: prefix.trim().to_string(),
+        dir: PathBuf::from(dir.trim()),
+        default,
+    })
+}
+
+/// The directory that backs the SPA shell -- the default site's own
+/// directory, which holds `index.html`/`404.html` directly.
+pub fn index_root(sites: &[SiteMapping]) -> PathBuf {
+    sites
+        .iter()
+        .find(|site| site.default)
+        .map(|site| site.dir.clone())
+        .unwrap_or_else(|| PathBuf::from("dist"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_plain_prefix_dir_pair() {
+        let site = parse_site("/docs=dist/docs").unwrap();
+        assert_eq!(site.prefix, "/docs");
+        assert_eq!(site.dir, PathBuf::from("dist/docs"));
+        assert!(!site.default);
+    }
+
+    #[test]
+    fn parses_the_default_marker() {
+        let site = parse_site("/docs=site-docs:default").unwrap();
+        assert_eq!(site.dir, PathBuf::from("site-docs"));
+        assert!(site.default);
+    }
+
+    #[test]
+    fn rejects_an_empty_entry() {
+        assert!(parse_site("").is_none());
+    }
+
+    #[test]
+    fn rejects_an_entry_missing_the_equals_sign() {
+        assert!(parse_site("/docs dist/docs").is_none());
+    }
+
+    #[test]
+    fn a_colon_in_the_directory_itself_does_not_trip_the_default_marker() {
+        let site = parse_site("/docs=dist:docs").unwrap();
+        assert_eq!(site.dir, PathBuf::from("dist:docs"));
+        assert!(!site.default);
+    }
+
+    #[test]
+    fn index_root_is_the_default_sites_own_directory() {
+        let sites = vec![
+            SiteMapping {
+                prefix: "/status".to_string(),
+                dir: PathBuf::from("dist/status"),
+                default: false,
+            },
+            SiteMapping {
+                prefix: "/docs".to_string(),
+                dir: PathBuf::from("site-docs"),
+                default: true,
+            },
+        ];
+
+        assert_eq!(index_root(&sites), PathBuf::from("site-docs"));
+    }
+}
@@ -0,0 +1,34 @@
+use std::path::PathBuf;
+
+use tokio::sync::RwLock;
+
+use tera::Tera;
+
+use crate::config::AppConfig;
+use crate::content::Page;
+
+/// Shared application state injected into handlers via `Router::with_state`.
+///
+/// `index_html` and `pages` are reloadable at runtime (see `admin::reload`),
+/// so they're held behind a lock rather than baked in at startup.
+pub struct AppState {
+    pub config: AppConfig,
+    /// Directory backing the SPA shell, i.e. where `index.html`/`404.html` live.
+    pub static_root: PathBuf,
+    pub pages: RwLock<Vec<Page>>,
+    pub index_html: RwLock<String>,
+    pub tera: Tera,
+}
+
+impl AppState {
+    pub fn new(config: AppConfig, static_root: PathBuf, pages: Vec<Page>, index_html: String) -> Self {
+        let tera = Tera::new("templates/**/*.tera").expect("failed to compile templates");
+        Self {
+            config,
+            static_root,
+            pages: RwLock::new(pages),
+            index_html: RwLock::new(index_html),
+            tera,
+        }
+    }
+}